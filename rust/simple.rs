@@ -1,14 +1,69 @@
 // Simple Rust example
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Language {
+    English,
+    Spanish,
+    Chinese,
+    Japanese,
+    Korean,
+    Hungarian,
+}
+
+fn greeting_template(lang: Language) -> &'static str {
+    match lang {
+        Language::English => "Hello, {}!",
+        Language::Spanish => "¡Hola, {}!",
+        Language::Chinese => "你好，{}！",
+        Language::Japanese => "こんにちは、{}！",
+        Language::Korean => "안녕하세요, {}!",
+        Language::Hungarian => "Helló, {}!",
+    }
+}
+
+fn farewell_template(lang: Language) -> &'static str {
+    match lang {
+        Language::English => "Goodbye, {}!",
+        Language::Spanish => "¡Adiós, {}!",
+        Language::Chinese => "再见，{}！",
+        Language::Japanese => "さようなら、{}！",
+        Language::Korean => "안녕히 가세요, {}!",
+        Language::Hungarian => "Viszlát, {}!",
+    }
+}
+
+fn greet_in(name: &str, lang: Language) -> String {
+    greeting_template(lang).replace("{}", name)
+}
+
+fn farewell_in(name: &str, lang: Language) -> String {
+    farewell_template(lang).replace("{}", name)
+}
+
 fn greet(name: &str) -> String {
-    format!("Hello, {}!", name)
+    greet_in(name, Language::English)
 }
 
 fn farewell(name: &str) -> String {
-    format!("Goodbye, {}!", name)
+    farewell_in(name, Language::English)
 }
 
+const ALL_LANGUAGES: [Language; 6] = [
+    Language::English,
+    Language::Spanish,
+    Language::Chinese,
+    Language::Japanese,
+    Language::Korean,
+    Language::Hungarian,
+];
+
 fn main() {
     let message = greet("World");
     println!("{}", message);
     println!("{}", farewell("World"));
+
+    for &lang in ALL_LANGUAGES.iter() {
+        println!("{}", greet_in("World", lang));
+        println!("{}", farewell_in("World", lang));
+    }
 }
@@ -1,25 +1,132 @@
 // Rust struct example
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Language {
+    English,
+    Spanish,
+    Chinese,
+    Japanese,
+    Korean,
+    Hungarian,
+}
+
+fn greeting_template(lang: Language) -> &'static str {
+    match lang {
+        Language::English => "Hello, my name is {name} and I'm {age} years old",
+        Language::Spanish => "Hola, me llamo {name} y tengo {age} años",
+        Language::Chinese => "你好，我叫{name}，今年{age}岁",
+        Language::Japanese => "こんにちは、{name}です。{age}歳です",
+        Language::Korean => "안녕하세요, 저는 {name}이고 {age}살입니다",
+        Language::Hungarian => "Helló, {name} vagyok, {age} éves",
+    }
+}
+
+#[derive(Debug)]
 struct Person {
     name: String,
     age: u8,
 }
 
 impl Person {
+    fn new(name: impl Into<String>, age: u8) -> Person {
+        Person {
+            name: name.into(),
+            age,
+        }
+    }
+
     fn greet(&self) -> String {
         format!("Hello, my name is {} and I'm {} years old", self.name, self.age)
     }
 
-    fn birthday(&mut self) -> String {
-        self.age += 1;
-        format!("Happy Birthday! You are now {} years old", self.age)
+    fn greet_in(&self, lang: Language) -> String {
+        greeting_template(lang)
+            .replace("{name}", &self.name)
+            .replace("{age}", &self.age.to_string())
+    }
+
+    fn birthday(&mut self) -> Result<String, AgeError> {
+        let new_age = self.age.checked_add(1).ok_or(AgeError::MaxAgeExceeded)?;
+        self.age = new_age;
+        Ok(format!("Happy Birthday! You are now {} years old", self.age))
+    }
+}
+
+impl fmt::Display for Person {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.name, self.age)
+    }
+}
+
+#[derive(Debug)]
+enum AgeError {
+    MaxAgeExceeded,
+}
+
+impl fmt::Display for AgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AgeError::MaxAgeExceeded => write!(f, "age cannot exceed {}", u8::MAX),
+        }
+    }
+}
+
+impl std::error::Error for AgeError {}
+
+const ALL_LANGUAGES: [Language; 6] = [
+    Language::English,
+    Language::Spanish,
+    Language::Chinese,
+    Language::Japanese,
+    Language::Korean,
+    Language::Hungarian,
+];
+
+#[derive(Default)]
+struct PersonBuilder {
+    name: Option<String>,
+    age: Option<u8>,
+}
+
+impl PersonBuilder {
+    fn new() -> Self {
+        PersonBuilder::default()
+    }
+
+    fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    fn age(mut self, age: u8) -> Self {
+        self.age = Some(age);
+        self
+    }
+
+    fn build(self) -> Person {
+        Person {
+            name: self.name.unwrap_or_default(),
+            age: self.age.unwrap_or_default(),
+        }
     }
 }
 
 fn main() {
-    let mut person = Person {
-        name: String::from("Eve"),
-        age: 45,
-    };
+    let mut person = Person::new("Eve", 45);
     println!("{}", person.greet());
-    println!("{}", person.birthday());
+    match person.birthday() {
+        Ok(message) => println!("{}", message),
+        Err(err) => println!("Couldn't celebrate: {}", err),
+    }
+    println!("{}", person);
+    println!("{:?}", person);
+    println!("{:#?}", person);
+    dbg!(&person);
+
+    let built = PersonBuilder::new().name("Adam").age(30).build();
+    println!("{}", built.greet());
+    for &lang in ALL_LANGUAGES.iter() {
+        println!("{}", built.greet_in(lang));
+    }
 }